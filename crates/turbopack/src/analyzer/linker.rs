@@ -1,49 +1,234 @@
 use anyhow::Result;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{
+        hash_map::DefaultHasher,
+        HashMap, HashSet,
+    },
     future::Future,
-    mem::take,
+    hash::{Hash, Hasher},
+    mem::{discriminant, take},
     pin::Pin,
-    sync::{Arc, Mutex},
-    time::Instant,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
 };
 use swc_ecmascript::utils::Id;
 
 use super::{graph::VarGraph, JsValue};
 
+/// Options controlling a single [`link`] call, in particular the knobs used
+/// to bound how much work resolution is allowed to do.
+pub struct LinkOptions {
+    /// The maximum number of `JsValue` nodes that may be processed before
+    /// resolution is truncated. Guards against combinatorial blow-up from
+    /// deeply nested or self-referential values.
+    pub fuel: usize,
+    /// A soft wall-clock deadline. Checked at each variable resolution and,
+    /// once passed, short-circuits remaining work the same way fuel
+    /// exhaustion does, so a single slow-to-resolve module can't blow past
+    /// its time budget just because it still had fuel left.
+    pub deadline: Option<Instant>,
+    /// When `true`, `link` additionally records the wall-clock time spent
+    /// resolving each `VarGraph` variable's subtree, so callers can find the
+    /// handful of variables that dominate analysis time.
+    pub collect_timings: bool,
+}
+
+impl Default for LinkOptions {
+    fn default() -> Self {
+        Self {
+            fuel: 100_000,
+            deadline: None,
+            collect_timings: false,
+        }
+    }
+}
+
+/// Cross-cutting state threaded through every `link_internal` call: the
+/// remaining fuel budget, an optional deadline, and an optional sink for
+/// per-variable timing.
+struct LinkContext<'a> {
+    fuel: AtomicUsize,
+    deadline: Option<Instant>,
+    timings: Option<&'a Mutex<HashMap<Id, Duration>>>,
+}
+
+/// A cached `link_internal` result together with the set of `VarGraph`
+/// variables it was derived from, so it can be invalidated precisely when
+/// one of those variables changes.
+struct CacheEntry {
+    value: JsValue,
+    deps: HashSet<Id>,
+}
+
 pub struct LinkCache {
-    inner: HashMap<Id, JsValue>,
+    inner: HashMap<Id, CacheEntry>,
+    /// Content-addressed cache for non-`Variable` nodes, keyed on a 128-bit
+    /// structural fingerprint of the input value so that structurally
+    /// identical sub-expressions reached through different paths (e.g. two
+    /// variables holding the same inlined constant) only get linked once.
+    structural: HashMap<u128, CacheEntry>,
 }
 
 impl LinkCache {
     pub fn new() -> Self {
         Self {
             inner: HashMap::new(),
+            structural: HashMap::new(),
         }
     }
 
-    fn store(&mut self, id: Id, value: JsValue) {
-        self.inner.insert(id, value);
+    fn store(&mut self, id: Id, value: JsValue, deps: HashSet<Id>) {
+        self.inner.insert(id, CacheEntry { value, deps });
+    }
+
+    fn get(&self, id: &Id) -> Option<(JsValue, HashSet<Id>)> {
+        self.inner
+            .get(id)
+            .map(|entry| (entry.value.clone(), entry.deps.clone()))
+    }
+
+    fn store_structural(&mut self, key: u128, value: JsValue, deps: HashSet<Id>) {
+        self.structural.insert(key, CacheEntry { value, deps });
     }
 
-    fn get(&self, id: &Id) -> Option<JsValue> {
-        self.inner.get(id).cloned()
+    fn get_structural(&self, key: u128) -> Option<(JsValue, HashSet<Id>)> {
+        self.structural
+            .get(&key)
+            .map(|entry| (entry.value.clone(), entry.deps.clone()))
+    }
+
+    /// Drops every cache entry whose recorded dependencies intersect
+    /// `changed`. Since an entry's dependency set is the full transitive
+    /// union of variables read while computing it (accumulated the same way
+    /// `replaced_circular_references` is), a single pass already removes
+    /// entries that depended on a dropped variable, whether directly or
+    /// transitively through an intermediate variable.
+    pub fn invalidate(&mut self, changed: &HashSet<Id>) {
+        self.inner
+            .retain(|id, entry| !changed.contains(id) && entry.deps.is_disjoint(changed));
+        self.structural
+            .retain(|_, entry| entry.deps.is_disjoint(changed));
     }
 }
 
+impl JsValue {
+    /// Computes a stable 128-bit fingerprint of this value for use as a
+    /// content-addressed cache key, mixing each node's discriminant with the
+    /// fingerprints of its children (the same idea rustc uses for its
+    /// `Fingerprint` hashing of interned structures). `Unknown` mixes in
+    /// both its wrapped payload and its reason string, since two unknowns
+    /// with different reasons must not collide.
+    ///
+    /// Composite variants recurse through each child's own `fingerprint_into`
+    /// and format only a copy of the node whose children are replaced by a
+    /// placeholder, so no subtree is formatted more than once. That copy is
+    /// made with a full `clone`, so each node still pays for cloning its
+    /// whole subtree.
+    async fn fingerprint(&self) -> Result<u128> {
+        // Both halves use `DefaultHasher::new()`, which has fixed keys, so
+        // equal values get equal fingerprints on every call. `hi` is fed a
+        // leading byte that `lo` is not, so the two 64-bit halves differ.
+        let lo = Mutex::new(DefaultHasher::new());
+        let hi = Mutex::new({
+            let mut hasher = DefaultHasher::new();
+            1u8.hash(&mut hasher);
+            hasher
+        });
+        self.fingerprint_into(&lo, &hi).await?;
+        let lo = lo.into_inner().unwrap().finish();
+        let hi = hi.into_inner().unwrap().finish();
+        Ok(((lo as u128) << 64) | hi as u128)
+    }
+
+    fn fingerprint_into<'b>(
+        &'b self,
+        lo: &'b Mutex<DefaultHasher>,
+        hi: &'b Mutex<DefaultHasher>,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'b>> {
+        Box::pin(async move {
+            discriminant(self).hash(&mut *lo.lock().unwrap());
+            discriminant(self).hash(&mut *hi.lock().unwrap());
+            match self {
+                JsValue::Unknown(inner, reason) => {
+                    reason.hash(&mut *lo.lock().unwrap());
+                    reason.hash(&mut *hi.lock().unwrap());
+                    match inner {
+                        Some(inner) => inner.fingerprint_into(lo, hi).await?,
+                        None => {
+                            0u8.hash(&mut *lo.lock().unwrap());
+                            0u8.hash(&mut *hi.lock().unwrap());
+                        }
+                    }
+                }
+                _ => {
+                    // Fingerprint each child through its own
+                    // `fingerprint_into`, folding the result directly into
+                    // `lo`/`hi`, then replace it with a tiny sentinel before
+                    // hashing a shallow clone of this node. That shallow
+                    // hash still mixes in this node's own payload (operator
+                    // kind, constant value, property name, ...) without
+                    // re-serializing subtrees that were already visited.
+                    let mut shallow = self.clone();
+                    shallow
+                        .for_each_children_async(&mut |child| {
+                            Box::pin(async move {
+                                child.fingerprint_into(lo, hi).await?;
+                                Ok((JsValue::Unknown(None, "fingerprinted"), true))
+                            })
+                        })
+                        .await?;
+                    format!("{:?}", shallow).hash(&mut *lo.lock().unwrap());
+                    format!("{:?}", shallow).hash(&mut *hi.lock().unwrap());
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// The result of resolving a `JsValue` against a `VarGraph`.
+struct Linked {
+    value: JsValue,
+    /// Circular variable references this value still depends on, if any.
+    circular: Option<HashSet<Id>>,
+    /// Whether resolution was truncated because the fuel budget ran out or
+    /// the deadline passed. A `true` value means `value` contains at least
+    /// one `JsValue::Unknown("linking budget exceeded")` or
+    /// `JsValue::Unknown("link deadline exceeded")` substitution and
+    /// therefore must not be cached, since a later call with more fuel or
+    /// time could produce a more complete result for the same input.
+    truncated: bool,
+    /// Every `VarGraph` variable `Id` that was read while computing `value`,
+    /// used to invalidate cache entries precisely when one of them changes.
+    deps: HashSet<Id>,
+}
+
 pub(crate) async fn link<'a, F, R>(
     graph: &VarGraph,
     mut val: JsValue,
     visitor: &F,
     cache: &Mutex<LinkCache>,
-) -> Result<JsValue>
+    options: &LinkOptions,
+) -> Result<(JsValue, HashMap<Id, Duration>)>
 where
     R: 'a + Future<Output = Result<(JsValue, bool)>> + Send,
     F: 'a + Fn(JsValue) -> R + Sync,
 {
     val.normalize();
-    let (val, _) = link_internal(graph, val, visitor, cache, &mut HashSet::new()).await?;
-    Ok(val)
+    let timings = options.collect_timings.then(|| Mutex::new(HashMap::new()));
+    let ctx = LinkContext {
+        fuel: AtomicUsize::new(options.fuel),
+        deadline: options.deadline,
+        timings: timings.as_ref(),
+    };
+    let linked = link_internal(graph, val, visitor, cache, &mut HashSet::new(), &ctx).await?;
+    Ok((
+        linked.value,
+        timings.map(|t| t.into_inner().unwrap()).unwrap_or_default(),
+    ))
 }
 
 fn link_internal_boxed<'b, 'a: 'b, F, R>(
@@ -52,21 +237,41 @@ fn link_internal_boxed<'b, 'a: 'b, F, R>(
     visitor: &'b F,
     cache: &'b Mutex<LinkCache>,
     circle_stack: &'b mut HashSet<Id>,
-) -> Pin<Box<dyn Future<Output = Result<(JsValue, Option<HashSet<Id>>)>> + Send + 'b>>
+    ctx: &'b LinkContext<'b>,
+) -> Pin<Box<dyn Future<Output = Result<Linked>> + Send + 'b>>
 where
     R: 'a + Future<Output = Result<(JsValue, bool)>> + Send,
     F: 'a + Fn(JsValue) -> R + Sync,
 {
-    Box::pin(link_internal(graph, val, visitor, cache, circle_stack))
+    Box::pin(link_internal(graph, val, visitor, cache, circle_stack, ctx))
 }
 
+/// Charges one unit of fuel for a node that is about to be processed. A
+/// `Variable` served from the by-`Id` cache is not charged; a non-`Variable`
+/// node is charged before its structural cache lookup, hit or miss. Returns
+/// `false` once the budget is exhausted.
+fn charge_fuel(ctx: &LinkContext) -> bool {
+    ctx.fuel
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |f| {
+            if f == 0 {
+                None
+            } else {
+                Some(f - 1)
+            }
+        })
+        .is_ok()
+}
+
+/// Resolves `val` against `graph`. See [`Linked`] for the shape of the
+/// result.
 pub(crate) async fn link_internal<'a, F, R>(
     graph: &'a VarGraph,
     val: JsValue,
     visitor: &'a F,
     cache: &Mutex<LinkCache>,
     circle_stack: &'a mut HashSet<Id>,
-) -> Result<(JsValue, Option<HashSet<Id>>)>
+    ctx: &'a LinkContext<'a>,
+) -> Result<Linked>
 where
     R: 'a + Future<Output = Result<(JsValue, bool)>> + Send,
     F: 'a + Fn(JsValue) -> R + Sync,
@@ -75,19 +280,51 @@ where
         JsValue::Variable(var) => {
             // Replace with unknown for now
             if circle_stack.contains(&var) {
-                Ok((
-                    JsValue::Unknown(
+                Ok(Linked {
+                    value: JsValue::Unknown(
                         Some(Arc::new(JsValue::Variable(var.clone()))),
                         "circular variable reference",
                     ),
-                    Some(HashSet::from([var])),
-                ))
+                    circular: Some(HashSet::from([var.clone()])),
+                    truncated: false,
+                    deps: HashSet::from([var]),
+                })
             } else {
                 {
-                    if let Some(value) = cache.lock().unwrap().get(&var) {
-                        return Ok((value, Some(HashSet::new())));
+                    if let Some((value, mut deps)) = cache.lock().unwrap().get(&var) {
+                        deps.insert(var);
+                        return Ok(Linked {
+                            value,
+                            circular: Some(HashSet::new()),
+                            truncated: false,
+                            deps,
+                        });
                     }
                 }
+                // The deadline and fuel budget only bound new work; a cache
+                // hit above is already free and must not be thrown away.
+                if ctx.deadline.map_or(false, |deadline| Instant::now() >= deadline) {
+                    return Ok(Linked {
+                        value: JsValue::Unknown(
+                            Some(Arc::new(JsValue::Variable(var))),
+                            "link deadline exceeded",
+                        ),
+                        circular: None,
+                        truncated: true,
+                        deps: HashSet::new(),
+                    });
+                }
+                if !charge_fuel(ctx) {
+                    return Ok(Linked {
+                        value: JsValue::Unknown(
+                            Some(Arc::new(JsValue::Variable(var))),
+                            "linking budget exceeded",
+                        ),
+                        circular: None,
+                        truncated: true,
+                        deps: HashSet::new(),
+                    });
+                }
                 circle_stack.insert(var.clone());
                 let val = if let Some(val) = graph.values.get(&var) {
                     val.clone()
@@ -97,24 +334,63 @@ where
                         "no value of this variable analysed",
                     )
                 };
-                let mut res = link_internal_boxed(graph, val, visitor, cache, circle_stack).await?;
-                if let Some(replaced_circular_references) = res.1.as_mut() {
-                    // Skip current var as it's internal to this resolution
-                    replaced_circular_references.remove(&var);
-                    if replaced_circular_references.is_empty() {
-                        cache.lock().unwrap().store(var.clone(), res.0.clone());
+                let started_at = ctx.timings.is_some().then(Instant::now);
+                let mut res =
+                    link_internal_boxed(graph, val, visitor, cache, circle_stack, ctx).await?;
+                if let (Some(started_at), Some(timings)) = (started_at, ctx.timings) {
+                    timings
+                        .lock()
+                        .unwrap()
+                        .entry(var.clone())
+                        .and_modify(|d| *d += started_at.elapsed())
+                        .or_insert_with(|| started_at.elapsed());
+                }
+                res.deps.insert(var.clone());
+                if !res.truncated {
+                    if let Some(circular) = res.circular.as_mut() {
+                        // Skip current var as it's internal to this resolution
+                        circular.remove(&var);
+                        if circular.is_empty() {
+                            cache
+                                .lock()
+                                .unwrap()
+                                .store(var.clone(), res.value.clone(), res.deps.clone());
+                        }
+                    } else {
+                        res.circular = Some(HashSet::new());
+                        cache
+                            .lock()
+                            .unwrap()
+                            .store(var.clone(), res.value.clone(), res.deps.clone());
                     }
-                } else {
-                    res.1 = Some(HashSet::new());
-                    cache.lock().unwrap().store(var.clone(), res.0.clone());
                 }
                 circle_stack.remove(&var);
-                // TODO: The result can be cached when
-                // res == None || replaced_circular_references.is_empty()
                 Ok(res)
             }
         }
         _ => {
+            // Unlike the `Variable` branch above, the structural cache lookup
+            // here isn't free: computing `val.fingerprint()` walks the whole
+            // subtree. Charge fuel *before* paying for that walk, otherwise a
+            // tight fuel budget does nothing to bound fingerprinting cost and
+            // a deep chain of misses ends up doing O(depth^2) work.
+            if !charge_fuel(ctx) {
+                return Ok(Linked {
+                    value: JsValue::Unknown(Some(Arc::new(val)), "linking budget exceeded"),
+                    circular: None,
+                    truncated: true,
+                    deps: HashSet::new(),
+                });
+            }
+            let structural_key = val.fingerprint().await?;
+            if let Some((value, deps)) = cache.lock().unwrap().get_structural(structural_key) {
+                return Ok(Linked {
+                    value,
+                    circular: Some(HashSet::new()),
+                    truncated: false,
+                    deps,
+                });
+            }
             async fn child_visitor<'b, 'a: 'b, F, R>(
                 child: JsValue,
                 graph: &'b VarGraph,
@@ -122,26 +398,39 @@ where
                 cache: &'b Mutex<LinkCache>,
                 circle_stack: &'b Mutex<HashSet<Id>>,
                 replaced_circular_references: &'b Mutex<HashSet<Id>>,
+                ctx: &'b LinkContext<'b>,
+                truncated: &'b Mutex<bool>,
+                deps: &'b Mutex<HashSet<Id>>,
             ) -> Result<(JsValue, bool)>
             where
                 R: 'a + Future<Output = Result<(JsValue, bool)>> + Send,
                 F: 'a + Fn(JsValue) -> R + Sync,
             {
                 let mut my_circle_stack = take(&mut *circle_stack.lock().unwrap());
-                let (mut value, res) =
-                    link_internal_boxed(graph, child, visitor, cache, &mut my_circle_stack).await?;
+                let mut linked =
+                    link_internal_boxed(graph, child, visitor, cache, &mut my_circle_stack, ctx)
+                        .await?;
                 *circle_stack.lock().unwrap() = my_circle_stack;
-                let modified = if let Some(res) = res {
-                    value.normalize_shallow();
-                    replaced_circular_references.lock().unwrap().extend(res);
+                if linked.truncated {
+                    *truncated.lock().unwrap() = true;
+                }
+                deps.lock().unwrap().extend(linked.deps);
+                let modified = if let Some(circular) = linked.circular {
+                    linked.value.normalize_shallow();
+                    replaced_circular_references
+                        .lock()
+                        .unwrap()
+                        .extend(circular);
                     true
                 } else {
                     false
                 };
-                Ok((value, modified))
+                Ok((linked.value, modified))
             }
             let replaced_circular_references = Mutex::new(HashSet::default());
             let circle_stack_mutex = Mutex::new(take(circle_stack));
+            let truncated = Mutex::new(false);
+            let deps = Mutex::new(HashSet::new());
             let (mut val, mut modified) = val
                 .for_each_children_async(&mut |child| {
                     Box::pin(child_visitor(
@@ -151,11 +440,16 @@ where
                         cache,
                         &circle_stack_mutex,
                         &replaced_circular_references,
+                        ctx,
+                        &truncated,
+                        &deps,
                     ))
                         as Pin<Box<dyn Future<Output = Result<(JsValue, bool)>> + Send>>
                 })
                 .await?;
             *circle_stack = circle_stack_mutex.into_inner().unwrap();
+            let truncated = truncated.into_inner().unwrap();
+            let deps = deps.into_inner().unwrap();
 
             if modified {
                 val.normalize_shallow();
@@ -173,16 +467,178 @@ where
                 }
             }
 
-            // TODO: The result can be cached when
-            // !modified || replaced_circular_references.is_empty()
             if modified {
-                Ok((
-                    val,
-                    Some(replaced_circular_references.into_inner().unwrap()),
-                ))
+                let replaced_circular_references = replaced_circular_references.into_inner().unwrap();
+                if !truncated && replaced_circular_references.is_empty() {
+                    cache
+                        .lock()
+                        .unwrap()
+                        .store_structural(structural_key, val.clone(), deps.clone());
+                }
+                Ok(Linked {
+                    value: val,
+                    circular: Some(replaced_circular_references),
+                    truncated,
+                    deps,
+                })
             } else {
-                Ok((val, None))
+                if !truncated {
+                    cache
+                        .lock()
+                        .unwrap()
+                        .store_structural(structural_key, val.clone(), deps.clone());
+                }
+                Ok(Linked {
+                    value: val,
+                    circular: None,
+                    truncated,
+                    deps,
+                })
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_atoms::JsWord;
+    use swc_common::SyntaxContext;
+
+    use super::*;
+
+    fn id(name: &str) -> Id {
+        (JsWord::from(name), SyntaxContext::empty())
+    }
+
+    #[test]
+    fn invalidate_drops_directly_dependent_entries() {
+        let mut cache = LinkCache::new();
+        let a = id("a");
+        cache.store(
+            a.clone(),
+            JsValue::Unknown(None, "a"),
+            HashSet::from([a.clone()]),
+        );
+
+        cache.invalidate(&HashSet::from([a.clone()]));
+
+        assert!(cache.get(&a).is_none());
+    }
+
+    #[test]
+    fn invalidate_drops_transitively_dependent_entries() {
+        let mut cache = LinkCache::new();
+        let inner = id("inner");
+        let outer = id("outer");
+        // `outer`'s cached value was computed by resolving `inner` along the
+        // way, so its recorded deps include both even though only `inner`
+        // changed directly.
+        cache.store(
+            outer.clone(),
+            JsValue::Unknown(None, "outer"),
+            HashSet::from([outer.clone(), inner.clone()]),
+        );
+
+        cache.invalidate(&HashSet::from([inner]));
+
+        assert!(cache.get(&outer).is_none());
+    }
+
+    #[test]
+    fn invalidate_keeps_unrelated_entries() {
+        let mut cache = LinkCache::new();
+        let unrelated = id("unrelated");
+        cache.store(
+            unrelated.clone(),
+            JsValue::Unknown(None, "unrelated"),
+            HashSet::from([unrelated.clone()]),
+        );
+
+        cache.invalidate(&HashSet::from([id("changed")]));
+
+        assert!(cache.get(&unrelated).is_some());
+    }
+
+    #[tokio::test]
+    async fn fuel_truncated_result_is_not_cached() {
+        let graph = VarGraph {
+            values: HashMap::new(),
+        };
+        let cache = Mutex::new(LinkCache::new());
+        let options = LinkOptions {
+            fuel: 0,
+            ..Default::default()
+        };
+        let visitor = |v: JsValue| async move { Ok((v, false)) };
+        let leaf = JsValue::Unknown(None, "leaf");
+        let key = leaf.fingerprint().await.unwrap();
+
+        let (value, _) = link(&graph, leaf, &visitor, &cache, &options)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            value,
+            JsValue::Unknown(_, "linking budget exceeded")
+        ));
+        assert!(cache.lock().unwrap().get_structural(key).is_none());
+    }
+
+    #[tokio::test]
+    async fn structurally_distinct_unknowns_do_not_collide() {
+        let a = JsValue::Unknown(None, "reason a");
+        let b = JsValue::Unknown(None, "reason b");
+        assert_ne!(a.fingerprint().await.unwrap(), b.fingerprint().await.unwrap());
+
+        let inner_x = JsValue::Unknown(Some(Arc::new(JsValue::Variable(id("x")))), "same reason");
+        let inner_y = JsValue::Unknown(Some(Arc::new(JsValue::Variable(id("y")))), "same reason");
+        assert_ne!(
+            inner_x.fingerprint().await.unwrap(),
+            inner_y.fingerprint().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn deadline_truncated_result_is_not_cached() {
+        let var = id("v");
+        let mut values = HashMap::new();
+        values.insert(var.clone(), JsValue::Unknown(None, "value"));
+        let graph = VarGraph { values };
+        let cache = Mutex::new(LinkCache::new());
+        // Any deadline already in the past by the time `link_internal` checks
+        // it will do; `Instant::now()` captured here is guaranteed to be <=
+        // the `Instant::now()` read inside the call.
+        let options = LinkOptions {
+            deadline: Some(Instant::now()),
+            ..Default::default()
+        };
+        let visitor = |v: JsValue| async move { Ok((v, false)) };
+
+        let (value, _) = link(&graph, JsValue::Variable(var.clone()), &visitor, &cache, &options)
+            .await
+            .unwrap();
+
+        assert!(matches!(value, JsValue::Unknown(_, "link deadline exceeded")));
+        assert!(cache.lock().unwrap().get(&var).is_none());
+    }
+
+    #[tokio::test]
+    async fn collect_timings_records_a_duration_per_resolved_variable() {
+        let var = id("v");
+        let mut values = HashMap::new();
+        values.insert(var.clone(), JsValue::Unknown(None, "value"));
+        let graph = VarGraph { values };
+        let cache = Mutex::new(LinkCache::new());
+        let options = LinkOptions {
+            collect_timings: true,
+            ..Default::default()
+        };
+        let visitor = |v: JsValue| async move { Ok((v, false)) };
+
+        let (_, timings) = link(&graph, JsValue::Variable(var.clone()), &visitor, &cache, &options)
+            .await
+            .unwrap();
+
+        assert!(timings.contains_key(&var));
+    }
+}